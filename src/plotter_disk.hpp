@@ -150,11 +150,11 @@ public:
             throw InvalidValueException("Stripe size too large");
         }
 
-#if defined(_WIN32) || defined(__x86_64__)
+#if defined(_MSC_VER) || defined(__x86_64__)
         if (phases_flags & ENABLE_BITFIELD && !Util::HavePopcnt()) {
             throw InvalidValueException("Bitfield plotting not supported by CPU");
         }
-#endif /* defined(_WIN32) || defined(__x86_64__) */
+#endif /* defined(_MSC_VER) || defined(__x86_64__) */
 
         std::cout << std::endl
                   << "Starting plotting progress into temporary dirs: " << tmp_dirname << " and "
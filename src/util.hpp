@@ -38,6 +38,9 @@ constexpr inline Int cdiv(Int a, Int2 b) { return (a + b - 1) / b; }
 #define NOMINMAX
 #include <windows.h>
 #include <processthreadsapi.h>
+#endif
+
+#if defined(_MSC_VER)
 #include "uint128_t.h"
 #else
 // __uint__128_t is only available in 64 bit architectures and on certain
@@ -74,8 +77,8 @@ inline uint64_t bswap_64(uint64_t x) { return __builtin_bswap64(x); }
 #error "unknown compiler, don't know how to swap bytes"
 #endif
 
-/* Platform-specific cpuid include. */
-#if defined(_WIN32)
+/* Compiler-specific cpuid include. */
+#if defined(_MSC_VER)
 #include <intrin.h>
 #elif defined(__x86_64__)
 #include <cpuid.h>
@@ -340,14 +343,14 @@ namespace Util {
         return b;
     }
 
-#if defined(_WIN32) || defined(__x86_64__)
+#if defined(_MSC_VER) || defined(__x86_64__)
     void CpuID(uint32_t leaf, uint32_t *regs)
     {
-#if defined(_WIN32)
+#if defined(_MSC_VER)
         __cpuid((int *)regs, (int)leaf);
 #else
         __get_cpuid(leaf, &regs[0], &regs[1], &regs[2], &regs[3]);
-#endif /* defined(_WIN32) */
+#endif /* defined(_MSC_VER) */
     }
 
     bool HavePopcnt(void)
@@ -359,11 +362,11 @@ namespace Util {
         // Bit 23 of ECX indicates POPCNT instruction support
         return (regs[2] >> 23) & 1;
     }
-#endif /* defined(_WIN32) || defined(__x86_64__) */
+#endif /* defined(_MSC_VER) || defined(__x86_64__) */
 
     inline uint64_t PopCount(uint64_t n)
     {
-#if defined(_WIN32)
+#if defined(_MSC_VER)
         return __popcnt64(n);
 #elif defined(__x86_64__)
         uint64_t r;
@@ -371,7 +374,7 @@ namespace Util {
         return r;
 #else
         return __builtin_popcountl(n);
-#endif /* defined(_WIN32) ... defined(__x86_64__) */
+#endif /* defined(_MSC_VER) ... defined(__x86_64__) */
     }
 }
 